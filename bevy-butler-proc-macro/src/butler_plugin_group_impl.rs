@@ -0,0 +1,93 @@
+//! This file enables `#[butler_plugin_group]` to be used as follows
+//!
+//! - Attached to a unit struct, lists the member plugins (in order) that make up the group
+//! - `add_before(Existing, New)` / `add_after(Existing, New)` / `disable(Existing)` directives
+//! can be interspersed to reorder or disable members, mirroring `PluginGroupBuilder`
+
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse::{Parse, ParseStream}, punctuated::Punctuated, Error, Expr, ItemStruct, Path, Token};
+
+use crate::utils::get_crate;
+
+enum PluginGroupDirective {
+    Add(Path),
+    AddBefore(Path, Path),
+    AddAfter(Path, Path),
+    Disable(Path),
+}
+
+pub(crate) struct ButlerPluginGroupArgs {
+    directives: Vec<PluginGroupDirective>,
+}
+
+impl Parse for ButlerPluginGroupArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let exprs = Punctuated::<Expr, Token![,]>::parse_terminated(input)?;
+        let directives = exprs
+            .into_iter()
+            .map(|expr| match expr {
+                Expr::Path(expr_path) => Ok(PluginGroupDirective::Add(expr_path.path)),
+                Expr::Call(call) => {
+                    let func_ident = match *call.func {
+                        Expr::Path(ref func_path) => func_path.path.require_ident()?.clone(),
+                        _ => return Err(Error::new_spanned(&call.func, "Expected one of \"add_before\", \"add_after\", \"disable\"")),
+                    };
+                    let mut args = call.args.into_iter();
+                    let path_arg = |expr: Option<Expr>| -> syn::Result<Path> {
+                        match expr {
+                            Some(Expr::Path(expr_path)) => Ok(expr_path.path),
+                            _ => Err(Error::new(func_ident.span(), "Expected a plugin path")),
+                        }
+                    };
+                    match func_ident.to_string().as_str() {
+                        "add_before" => Ok(PluginGroupDirective::AddBefore(path_arg(args.next())?, path_arg(args.next())?)),
+                        "add_after" => Ok(PluginGroupDirective::AddAfter(path_arg(args.next())?, path_arg(args.next())?)),
+                        "disable" => Ok(PluginGroupDirective::Disable(path_arg(args.next())?)),
+                        other => Err(Error::new(func_ident.span(), format!("Unrecognized plugin group directive \"{other}\""))),
+                    }
+                }
+                other => Err(Error::new_spanned(other, "Expected a plugin path or an add_before/add_after/disable directive")),
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(Self { directives })
+    }
+}
+
+/// Implementation for `#[butler_plugin_group]` on struct definitions
+///
+/// ```
+/// # use bevy_butler_proc_macro::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct PluginA;
+/// # #[butler_plugin]
+/// # struct PluginB;
+/// #[butler_plugin_group(PluginA, PluginB)]
+/// struct MyGroup;
+/// ```
+pub(crate) fn macro_impl(args: ButlerPluginGroupArgs, item: ItemStruct) -> Result<proc_macro2::TokenStream, proc_macro2::TokenStream> {
+    let bevy_butler = get_crate("bevy-butler")
+        .map_err(|e| Error::new(Span::call_site(), e).to_compile_error())?;
+
+    let ident = &item.ident;
+
+    let builder_calls = args.directives.iter().map(|directive| match directive {
+        PluginGroupDirective::Add(plugin) => quote! { .add(#plugin) },
+        PluginGroupDirective::AddBefore(existing, new) => quote! { .add_before::<#existing>(#new) },
+        PluginGroupDirective::AddAfter(existing, new) => quote! { .add_after::<#existing>(#new) },
+        PluginGroupDirective::Disable(existing) => quote! { .disable::<#existing>() },
+    });
+
+    Ok(quote! {
+        #item
+
+        impl #bevy_butler::__internal::bevy_app::PluginGroup for #ident {
+            fn build(self) -> #bevy_butler::__internal::bevy_app::PluginGroupBuilder {
+                #bevy_butler::__internal::bevy_app::PluginGroupBuilder::start::<Self>()
+                    #(#builder_calls)*
+            }
+        }
+    }.into())
+}
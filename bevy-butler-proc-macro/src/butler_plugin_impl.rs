@@ -0,0 +1,137 @@
+//! This file enables `#[butler_plugin]` to be used as follows
+//!
+//! - Generates a `Plugin` impl for the annotated struct
+//! - Registers the struct's `TypeId` as a key into the `ButlerRegistry`,
+//! draining any free-standing `#[system]`-style registrations that
+//! targeted it into the matching lifecycle method
+
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse::{Parse, ParseStream}, Error, Expr, ItemStruct, Meta, Token};
+
+use crate::utils::get_crate;
+
+/// Parsed arguments to `#[butler_plugin(...)]`
+///
+/// All four keys are optional and mirror the four stages of
+/// [`bevy_app::Plugin`]: `build`, `ready`, `finish`, `cleanup`.
+pub(crate) struct ButlerPluginArgs {
+    pub build: Option<Expr>,
+    pub ready: Option<Expr>,
+    pub finish: Option<Expr>,
+    pub cleanup: Option<Expr>,
+}
+
+impl Parse for ButlerPluginArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = Self {
+            build: None,
+            ready: None,
+            finish: None,
+            cleanup: None,
+        };
+
+        loop {
+            if input.is_empty() {
+                break;
+            }
+
+            let meta = input.parse::<Meta>()?;
+            let name_value = meta.require_name_value()?;
+            let slot = match name_value.path
+                .get_ident()
+                .ok_or(input.error("Expected a name-value identifier"))?
+                .to_string()
+                .as_str()
+            {
+                "build" => &mut args.build,
+                "ready" => &mut args.ready,
+                "finish" => &mut args.finish,
+                "cleanup" => &mut args.cleanup,
+                other => return Err(input.error(format!("Unrecognized butler_plugin argument \"{other}\""))),
+            };
+
+            if slot.is_some() {
+                return Err(input.error("Argument defined more than once"));
+            }
+            *slot = Some(name_value.value.clone());
+
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(args)
+    }
+}
+
+/// Implementation for `#[butler_plugin]` on struct definitions
+///
+/// Each of `build`/`ready`/`finish`/`cleanup` is a method to chain onto the `App`
+/// (or, for `ready`, onto the `&App`), exactly like `build = init_resource::<Counter>`
+/// already works: it expands to `app.init_resource::<Counter>();`.
+///
+/// ```
+/// # use bevy_butler_proc_macro::*;
+/// # use bevy::prelude::*;
+/// #[butler_plugin(build = init_resource::<Counter>, finish = init_resource::<Ready>)]
+/// struct MyPlugin;
+/// # #[derive(Resource, Default)]
+/// # struct Counter(pub u8);
+/// # #[derive(Resource, Default)]
+/// # struct Ready;
+/// ```
+pub(crate) fn macro_impl(args: ButlerPluginArgs, item: ItemStruct) -> Result<proc_macro2::TokenStream, proc_macro2::TokenStream> {
+    let bevy_butler = get_crate("bevy-butler")
+        .map_err(|e| Error::new(Span::call_site(), e).to_compile_error())?;
+
+    let ident = &item.ident;
+
+    let build_call = args.build.map(|expr| quote! { app.#expr(); });
+    let finish_call = args.finish.map(|expr| quote! { app.#expr(); });
+    let cleanup_call = args.cleanup.map(|expr| quote! { app.#expr(); });
+
+    // Only override `ready`'s default (`true`) when the user actually supplied one;
+    // otherwise the generated method would have an unused `app` parameter.
+    let ready_method = args.ready.map(|expr| quote! {
+        fn ready(&self, app: &#bevy_butler::__internal::bevy_app::App) -> bool {
+            app.#expr()
+        }
+    });
+
+    Ok(quote! {
+        #item
+
+        impl #bevy_butler::__internal::bevy_app::Plugin for #ident {
+            fn build(&self, app: &mut #bevy_butler::__internal::bevy_app::App) {
+                #build_call
+                #bevy_butler::__internal::run_butler_phase(
+                    app,
+                    std::any::TypeId::of::<Self>(),
+                    #bevy_butler::__internal::ButlerPhase::Build,
+                );
+            }
+
+            #ready_method
+
+            fn finish(&self, app: &mut #bevy_butler::__internal::bevy_app::App) {
+                #finish_call
+                #bevy_butler::__internal::run_butler_phase(
+                    app,
+                    std::any::TypeId::of::<Self>(),
+                    #bevy_butler::__internal::ButlerPhase::Finish,
+                );
+            }
+
+            fn cleanup(&self, app: &mut #bevy_butler::__internal::bevy_app::App) {
+                #cleanup_call
+                #bevy_butler::__internal::run_butler_phase(
+                    app,
+                    std::any::TypeId::of::<Self>(),
+                    #bevy_butler::__internal::ButlerPhase::Cleanup,
+                );
+            }
+        }
+    }.into())
+}
@@ -0,0 +1,67 @@
+//! This file enables `#[butler_system_set]` to be used as follows
+//!
+//! - Attached to a unit struct that already derives `SystemSet`
+//! - Registers a single `app.configure_sets(schedule, MySet.run_if(...).after(OtherSet))`
+//! call into the target plugin's `BUTLER_SLICE` entry, so the set's ordering and run
+//! conditions are declared once instead of being repeated on every member `#[system]`
+
+use proc_macro2::Span;
+use quote::{format_ident, quote, TokenStreamExt};
+use syn::{Error, ItemStruct, Token};
+
+use crate::system_impl::{to_pascal_case, SystemArgs};
+use crate::utils::get_crate;
+
+/// Implementation for `#[butler_system_set(schedule = ..., plugin = ..., ...)]`
+///
+/// ```
+/// # use bevy_butler_proc_macro::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(SystemSet, Debug, Hash, Eq, PartialEq, Clone)]
+/// #[butler_system_set(schedule = Update, plugin = MyPlugin, after = OtherSet)]
+/// struct MySet;
+/// # #[derive(SystemSet, Debug, Hash, Eq, PartialEq, Clone)]
+/// # struct OtherSet;
+/// ```
+pub(crate) fn macro_impl(args: SystemArgs, item: ItemStruct) -> Result<proc_macro2::TokenStream, proc_macro2::TokenStream> {
+    let schedule = args.schedule
+        .ok_or_else(|| Error::new(Span::call_site(), "#[butler_system_set] requires a `schedule`").into_compile_error())?;
+    let plugin = args.plugin
+        .ok_or_else(|| Error::new(Span::call_site(), "#[butler_system_set] requires a `plugin`").into_compile_error())?;
+    let phase = args.phase
+        .unwrap_or_else(|| format_ident!("build"));
+    let phase_variant = format_ident!("{}", to_pascal_case(&phase.to_string()));
+
+    let bevy_butler = get_crate("bevy-butler")
+        .map_err(|e| Error::new(Span::call_site(), e).to_compile_error())?;
+
+    let set_name = &item.ident;
+
+    let transforms = if args.transforms.is_empty() {
+        None
+    } else {
+        let transform_iter = args.transforms
+            .into_iter()
+            .map(|(path, expr)| quote! { #path(#expr) });
+        let mut transforms = quote! { . };
+        transforms.append_separated(transform_iter, Token![.](Span::call_site()));
+        Some(transforms)
+    };
+
+    let butler_func_name = format_ident!("__butler_set_{}", set_name);
+
+    Ok(quote! {
+        #item
+
+        #[#bevy_butler::__internal::linkme::distributed_slice(#bevy_butler::__internal::BUTLER_SLICE)]
+        #[linkme(crate = #bevy_butler::__internal::linkme)]
+        #[allow(non_upper_case_globals)]
+        static #butler_func_name: #bevy_butler::__internal::ButlerFunc = |registry: &mut #bevy_butler::__internal::ButlerRegistry| {
+            registry.entry((std::any::TypeId::of::<#plugin>(), #bevy_butler::__internal::ButlerPhase::#phase_variant))
+                .or_default()
+                .push(|app| { app.configure_sets( #schedule, #set_name #transforms ); } );
+        };
+    }.into())
+}
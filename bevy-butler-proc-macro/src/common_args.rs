@@ -0,0 +1,54 @@
+//! Shared parsing for the `plugin = ...` and `phase = build | finish | cleanup`
+//! arguments common to `#[system]`, `#[resource]`, `#[event]`, `#[butler_system_set]`
+//! and `#[butler_state]`
+
+use syn::{parse::ParseStream, Error, Expr, ExprPath, Ident, Meta};
+
+/// The only valid `phase` values, matching `ButlerPhase`'s variants
+const PHASES: &[&str] = &["build", "finish", "cleanup"];
+
+/// Tries to interpret `meta` as the shared `plugin` or `phase` key, setting the
+/// matching field and returning `true` if it was handled. Returns `false` (with no
+/// error raised) for any other key, so callers can fall through to their own args.
+pub(crate) fn try_parse_common(
+    input: ParseStream,
+    meta: &Meta,
+    plugin: &mut Option<ExprPath>,
+    phase: &mut Option<Ident>,
+) -> syn::Result<bool> {
+    let Some(ident) = meta.path().get_ident() else {
+        return Ok(false);
+    };
+
+    match ident.to_string().as_str() {
+        "plugin" => {
+            if plugin.is_some() {
+                return Err(input.error("\"plugin\" defined more than once"));
+            }
+            else if let Expr::Path(path) = meta.require_name_value()?.value.clone() {
+                *plugin = Some(path);
+            }
+            else {
+                return Err(input.error("Expected a Plugin after \"plugin\""));
+            }
+            Ok(true)
+        },
+        "phase" => {
+            if phase.is_some() {
+                return Err(input.error("\"phase\" defined more than once"));
+            }
+            else if let Expr::Path(path) = meta.require_name_value()?.value.clone() {
+                let phase_ident = path.path.require_ident()?.clone();
+                if !PHASES.contains(&phase_ident.to_string().as_str()) {
+                    return Err(Error::new_spanned(&phase_ident, "Expected one of \"build\", \"finish\" or \"cleanup\" after \"phase\""));
+                }
+                *phase = Some(phase_ident);
+            }
+            else {
+                return Err(input.error("Expected one of \"build\", \"finish\" or \"cleanup\" after \"phase\""));
+            }
+            Ok(true)
+        },
+        _ => Ok(false),
+    }
+}
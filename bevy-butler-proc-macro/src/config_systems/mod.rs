@@ -1,8 +1,11 @@
 use proc_macro::TokenStream as TokenStream1;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use structs::ConfigSystemsInput;
-use syn::{parse::{Parse, Parser}, Attribute, Item, MetaList};
+use syn::{parse::{Parse, Parser}, Attribute, Error, Item, Meta, MetaList, Token};
+
+use crate::system_impl::{to_pascal_case, SystemArgs};
+use crate::utils::get_crate;
 
 mod structs;
 
@@ -11,6 +14,11 @@ pub(crate) const CONFIG_SYSTEMS_DEFAULT_ARGS_IDENT: &'static str = "_butler_conf
 pub(crate) fn macro_impl(body: TokenStream1) -> syn::Result<TokenStream2> {
     // Parse the arguments
     let input = ConfigSystemsInput::parse.parse(body)?;
+
+    if input.chain {
+        return chain_impl(input.system_args, input.items);
+    }
+
     let defaults = input.system_args;
     let mut items = input.items;
 
@@ -37,8 +45,17 @@ pub(crate) fn macro_impl(body: TokenStream1) -> syn::Result<TokenStream2> {
             Item::Fn(item_fn) => {
                 item_fn.attrs.push(config_attr.clone());
             }
-            // Could be `config_systems!`
-            Item::Macro(_item_macro) => todo!(),
+            // Could be a nested `config_systems!`, in which case our defaults become
+            // its defaults, overridden by whatever it defines itself
+            Item::Macro(item_macro) if item_macro.mac.path.is_ident("config_systems") => {
+                let nested = ConfigSystemsInput::parse.parse2(item_macro.mac.tokens.clone())?;
+                let merged_args = defaults.splat(&nested.system_args);
+                let nested_items = &nested.items;
+                let chain_marker = nested.chain.then(|| quote! { chain, });
+                item_macro.mac.tokens = quote! {
+                    #(#chain_marker)* #merged_args; #(#nested_items)*
+                };
+            }
             _ => (),
         }
     }
@@ -47,4 +64,88 @@ pub(crate) fn macro_impl(body: TokenStream1) -> syn::Result<TokenStream2> {
     Ok(quote! {
         #(#items)*
     })
-}
\ No newline at end of file
+}
+
+/// Instead of letting every contained `#[system]` push its own independent
+/// closure into `BUTLER_SLICE`, collect them into a single
+/// `app.add_systems(schedule, (a, b, c).chain())` registration that preserves
+/// source order, as requested by a leading `chain` marker in `config_systems!`.
+fn chain_impl(defaults: SystemArgs, items: Vec<Item>) -> syn::Result<TokenStream2> {
+    let bevy_butler = get_crate("bevy-butler")
+        .map_err(|e| Error::new(proc_macro2::Span::call_site(), e))?;
+
+    let schedule = defaults.schedule.clone()
+        .ok_or_else(|| Error::new(proc_macro2::Span::call_site(), "chained config_systems! requires either a defined or inherited `schedule`"))?;
+    let plugin = defaults.plugin.clone()
+        .ok_or_else(|| Error::new(proc_macro2::Span::call_site(), "chained config_systems! requires either a defined or inherited `plugin`"))?;
+    let phase = defaults.phase.clone()
+        .unwrap_or_else(|| format_ident!("build"));
+    let phase_variant = format_ident!("{}", to_pascal_case(&phase.to_string()));
+
+    let mut chained_systems = Vec::new();
+    let mut passthrough = Vec::new();
+    let mut first_sys_name = None;
+
+    for mut item in items {
+        let Item::Fn(item_fn) = &mut item else {
+            passthrough.push(item);
+            continue;
+        };
+
+        let Some(attr_pos) = item_fn.attrs.iter().position(|attr| attr.path().is_ident("system")) else {
+            passthrough.push(item);
+            continue;
+        };
+        let attr = item_fn.attrs.remove(attr_pos);
+
+        let own_args = match &attr.meta {
+            Meta::Path(_) => SystemArgs::parse.parse2(TokenStream2::new())?,
+            Meta::List(list) => SystemArgs::parse.parse2(list.tokens.clone())?,
+            Meta::NameValue(_) => return Err(Error::new_spanned(&attr, "Expected #[system] or #[system(...)]")),
+        };
+        if own_args.schedule.is_some() || own_args.on_enter.is_some() || own_args.on_exit.is_some() {
+            return Err(Error::new_spanned(&item_fn.sig.ident, "systems in a chained config_systems! all run on the group's schedule; a member cannot override \"schedule\", \"on_enter\" or \"on_exit\""));
+        }
+        if own_args.plugin.is_some() || own_args.phase.is_some() {
+            return Err(Error::new_spanned(&item_fn.sig.ident, "systems in a chained config_systems! are registered together under the group's \"plugin\" and \"phase\"; a member cannot override either"));
+        }
+        let merged = defaults.splat(&own_args);
+
+        let sys_name = item_fn.sig.ident.clone();
+        first_sys_name.get_or_insert_with(|| sys_name.clone());
+
+        let transform_iter = merged.transforms
+            .iter()
+            .map(|(path, expr)| quote! { #path(#expr) })
+            .chain(merged.set.clone().map(|set| quote! { in_set(#set) }));
+        let mut peekable = transform_iter.peekable();
+        let transforms = if peekable.peek().is_none() {
+            None
+        } else {
+            let mut transforms = quote! { . };
+            transforms.append_separated(peekable, Token![.](proc_macro2::Span::call_site()));
+            Some(transforms)
+        };
+
+        chained_systems.push(quote! { #sys_name #transforms });
+        passthrough.push(item);
+    }
+
+    let Some(first_sys_name) = first_sys_name else {
+        return Err(Error::new(proc_macro2::Span::call_site(), "chained config_systems! requires at least one #[system] fn"));
+    };
+    let group_name = format_ident!("__butler_chain_{}", first_sys_name);
+
+    Ok(quote! {
+        #(#passthrough)*
+
+        #[#bevy_butler::__internal::linkme::distributed_slice(#bevy_butler::__internal::BUTLER_SLICE)]
+        #[linkme(crate = #bevy_butler::__internal::linkme)]
+        #[allow(non_upper_case_globals)]
+        static #group_name: #bevy_butler::__internal::ButlerFunc = |registry: &mut #bevy_butler::__internal::ButlerRegistry| {
+            registry.entry((std::any::TypeId::of::<#plugin>(), #bevy_butler::__internal::ButlerPhase::#phase_variant))
+                .or_default()
+                .push(|app| { app.add_systems( #schedule, (#(#chained_systems),*).chain() ); } );
+        };
+    })
+}
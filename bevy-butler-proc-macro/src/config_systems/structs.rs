@@ -0,0 +1,53 @@
+use syn::{parse::{Parse, ParseStream, Parser}, Ident, Item, Token};
+
+use crate::system_impl::SystemArgs;
+
+/// The parsed body of a `config_systems! { ... }` invocation:
+///
+/// ```ignore
+/// config_systems!(schedule = Update, plugin = MyPlugin;
+///     #[system]
+///     fn a() { }
+///
+///     #[system(run_if = some_condition)]
+///     fn b() { }
+/// );
+/// ```
+///
+/// A leading `chain,` marker in the default-args header requests that the
+/// contained systems be registered as a single `(a, b, c).chain()` group,
+/// preserving source order, rather than as independent registrations.
+pub(crate) struct ConfigSystemsInput {
+    pub chain: bool,
+    pub system_args: SystemArgs,
+    pub items: Vec<Item>,
+}
+
+impl Parse for ConfigSystemsInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let chain = if input.peek(Ident) && input.fork().parse::<Ident>()? == "chain" {
+            input.parse::<Ident>()?;
+            if !input.peek(Token![;]) {
+                input.parse::<Token![,]>()?;
+            }
+            true
+        } else {
+            false
+        };
+
+        // The default args end at the first top-level `;`
+        let mut header = proc_macro2::TokenStream::new();
+        while !input.is_empty() && !input.peek(Token![;]) {
+            header.extend(std::iter::once(input.parse::<proc_macro2::TokenTree>()?));
+        }
+        let system_args = SystemArgs::parse.parse2(header)?;
+        input.parse::<Token![;]>()?;
+
+        let mut items = Vec::new();
+        while !input.is_empty() {
+            items.push(input.parse::<Item>()?);
+        }
+
+        Ok(Self { chain, system_args, items })
+    }
+}
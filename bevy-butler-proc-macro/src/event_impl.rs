@@ -0,0 +1,94 @@
+//! This file enables `#[event]` to be used on a message type, pushing an
+//! `app.add_event::<T>()` closure into the target plugin's `BUTLER_SLICE` entry,
+//! the same way `#[system]` registers systems
+
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{parse::{Parse, ParseStream}, Error, ExprPath, Ident, Item, Meta, Token};
+
+use crate::common_args::try_parse_common;
+use crate::system_impl::to_pascal_case;
+use crate::utils::get_crate;
+
+pub(crate) struct EventArgs {
+    pub plugin: Option<ExprPath>,
+    pub phase: Option<Ident>,
+}
+
+impl Parse for EventArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = Self {
+            plugin: None,
+            phase: None,
+        };
+
+        loop {
+            if input.is_empty() {
+                break;
+            }
+
+            let meta = input.parse::<Meta>()?;
+            if try_parse_common(input, &meta, &mut args.plugin, &mut args.phase)? {
+                if input.is_empty() {
+                    break;
+                }
+                input.parse::<Token![,]>()?;
+                continue;
+            }
+
+            let other = meta.path()
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .unwrap_or_else(|| "?".to_string());
+            return Err(input.error(format!("Unrecognized event argument \"{other}\"")));
+        }
+
+        Ok(args)
+    }
+}
+
+/// Implementation for `#[event]` on message type definitions
+///
+/// ```
+/// # use bevy_butler_proc_macro::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Event)]
+/// #[event(plugin = MyPlugin)]
+/// enum DamageTaken {
+///     Flat(u32),
+///     Percent(f32),
+/// }
+/// ```
+pub(crate) fn macro_impl(args: EventArgs, item: Item) -> Result<proc_macro2::TokenStream, proc_macro2::TokenStream> {
+    let plugin = args.plugin
+        .ok_or_else(|| Error::new(Span::call_site(), "#[event] requires either a defined or inherited `plugin`").into_compile_error())?;
+    let phase = args.phase
+        .unwrap_or_else(|| format_ident!("build"));
+    let phase_variant = format_ident!("{}", to_pascal_case(&phase.to_string()));
+
+    let bevy_butler = get_crate("bevy-butler")
+        .map_err(|e| Error::new(Span::call_site(), e).to_compile_error())?;
+
+    let ident = match &item {
+        Item::Enum(item_enum) => &item_enum.ident,
+        Item::Struct(item_struct) => &item_struct.ident,
+        _ => return Err(Error::new(Span::call_site(), "#[event] can only be applied to an enum or struct").to_compile_error()),
+    };
+
+    let butler_func_name = format_ident!("__butler_event_{}", ident);
+
+    Ok(quote! {
+        #item
+
+        #[#bevy_butler::__internal::linkme::distributed_slice(#bevy_butler::__internal::BUTLER_SLICE)]
+        #[linkme(crate = #bevy_butler::__internal::linkme)]
+        #[allow(non_upper_case_globals)]
+        static #butler_func_name: #bevy_butler::__internal::ButlerFunc = |registry: &mut #bevy_butler::__internal::ButlerRegistry| {
+            registry.entry((std::any::TypeId::of::<#plugin>(), #bevy_butler::__internal::ButlerPhase::#phase_variant))
+                .or_default()
+                .push(|app| { app.add_event::<#ident>(); } );
+        };
+    }.into())
+}
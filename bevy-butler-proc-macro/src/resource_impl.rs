@@ -0,0 +1,132 @@
+//! This file enables `#[resource]` to be used on a `#[derive(Resource)]` enum or struct,
+//! pushing an `app.init_resource::<T>()` or `app.insert_resource(...)` closure into
+//! the target plugin's `BUTLER_SLICE` entry, the same way `#[system]` registers systems
+
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{parse::{Parse, ParseStream}, Error, Expr, ExprPath, Ident, Item, Meta, Token};
+
+use crate::common_args::try_parse_common;
+use crate::system_impl::to_pascal_case;
+use crate::utils::get_crate;
+
+pub(crate) struct ResourceArgs {
+    pub plugin: Option<ExprPath>,
+    pub phase: Option<Ident>,
+    pub init: bool,
+    pub insert: Option<Expr>,
+}
+
+impl Parse for ResourceArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = Self {
+            plugin: None,
+            phase: None,
+            init: false,
+            insert: None,
+        };
+
+        loop {
+            if input.is_empty() {
+                break;
+            }
+
+            let meta = input.parse::<Meta>()?;
+            if try_parse_common(input, &meta, &mut args.plugin, &mut args.phase)? {
+                if input.is_empty() {
+                    break;
+                }
+                input.parse::<Token![,]>()?;
+                continue;
+            }
+
+            match &meta {
+                Meta::Path(path) if path.is_ident("init") => {
+                    if args.init {
+                        return Err(input.error("\"init\" defined more than once"));
+                    }
+                    args.init = true;
+                }
+                Meta::NameValue(name_value) => {
+                    match name_value.path
+                        .get_ident()
+                        .ok_or(input.error("Expected a name-value identifier"))?
+                        .to_string()
+                        .as_str()
+                    {
+                        "insert" => {
+                            if args.insert.is_some() {
+                                return Err(input.error("\"insert\" defined more than once"));
+                            }
+                            args.insert = Some(name_value.value.clone());
+                        },
+                        other => return Err(input.error(format!("Unrecognized resource argument \"{other}\""))),
+                    }
+                }
+                _ => return Err(input.error("Expected \"init\" or a name-value argument")),
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+
+        if !args.init && args.insert.is_none() {
+            return Err(input.error("#[resource] requires either \"init\" or \"insert = ...\""));
+        }
+        if args.init && args.insert.is_some() {
+            return Err(input.error("#[resource] cannot have both \"init\" and \"insert = ...\""));
+        }
+
+        Ok(args)
+    }
+}
+
+/// Implementation for `#[resource]` on `#[derive(Resource)]` types
+///
+/// ```
+/// # use bevy_butler_proc_macro::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Resource, Default)]
+/// #[resource(plugin = MyPlugin, init)]
+/// struct Counter(pub u8);
+/// ```
+pub(crate) fn macro_impl(args: ResourceArgs, item: Item) -> Result<proc_macro2::TokenStream, proc_macro2::TokenStream> {
+    let plugin = args.plugin
+        .ok_or_else(|| Error::new(Span::call_site(), "#[resource] requires either a defined or inherited `plugin`").into_compile_error())?;
+    let phase = args.phase
+        .unwrap_or_else(|| format_ident!("build"));
+    let phase_variant = format_ident!("{}", to_pascal_case(&phase.to_string()));
+
+    let bevy_butler = get_crate("bevy-butler")
+        .map_err(|e| Error::new(Span::call_site(), e).to_compile_error())?;
+
+    let ident = match &item {
+        Item::Enum(item_enum) => &item_enum.ident,
+        Item::Struct(item_struct) => &item_struct.ident,
+        _ => return Err(Error::new(Span::call_site(), "#[resource] can only be applied to an enum or struct").to_compile_error()),
+    };
+
+    let register_call = match args.insert {
+        Some(expr) => quote! { app.insert_resource(#expr); },
+        None => quote! { app.init_resource::<#ident>(); },
+    };
+
+    let butler_func_name = format_ident!("__butler_resource_{}", ident);
+
+    Ok(quote! {
+        #item
+
+        #[#bevy_butler::__internal::linkme::distributed_slice(#bevy_butler::__internal::BUTLER_SLICE)]
+        #[linkme(crate = #bevy_butler::__internal::linkme)]
+        #[allow(non_upper_case_globals)]
+        static #butler_func_name: #bevy_butler::__internal::ButlerFunc = |registry: &mut #bevy_butler::__internal::ButlerRegistry| {
+            registry.entry((std::any::TypeId::of::<#plugin>(), #bevy_butler::__internal::ButlerPhase::#phase_variant))
+                .or_default()
+                .push(|app| { #register_call } );
+        };
+    }.into())
+}
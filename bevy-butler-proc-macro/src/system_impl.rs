@@ -7,13 +7,18 @@
 
 use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
-use syn::{parse::{Parse, ParseStream}, Error, Expr, ExprPath, ItemFn, Meta, Path, Token};
+use syn::{parse::{Parse, ParseStream}, Error, Expr, ExprPath, Ident, ItemFn, Meta, Path, Token};
 
+use crate::common_args::try_parse_common;
 use crate::utils::get_crate;
 
 pub(crate) struct SystemArgs {
     pub schedule: Option<ExprPath>,
     pub plugin: Option<ExprPath>,
+    pub phase: Option<Ident>,
+    pub set: Option<ExprPath>,
+    pub on_enter: Option<Expr>,
+    pub on_exit: Option<Expr>,
     pub transforms: Vec<(Path, Expr)>,
 }
 
@@ -22,6 +27,10 @@ impl Parse for SystemArgs {
         let mut args = Self {
             schedule: None,
             plugin: None,
+            phase: None,
+            set: None,
+            on_enter: None,
+            on_exit: None,
             transforms: Default::default(),
         };
 
@@ -31,6 +40,14 @@ impl Parse for SystemArgs {
             }
             
             let meta = input.parse::<Meta>()?;
+            if try_parse_common(input, &meta, &mut args.plugin, &mut args.phase)? {
+                if input.is_empty() {
+                    break;
+                }
+                input.parse::<Token![,]>()?;
+                continue;
+            }
+
             let name_value = meta.require_name_value()?;
             match name_value.path
                 .get_ident()
@@ -49,16 +66,28 @@ impl Parse for SystemArgs {
                         return Err(input.error("Expected a Schedule after \"schedule\""));
                     }
                 },
-                "plugin" => {
-                    if args.plugin.is_some() {
-                        return Err(input.error("\"plugin\" defined more than once"));
+                "set" => {
+                    if args.set.is_some() {
+                        return Err(input.error("\"set\" defined more than once"));
                     }
                     else if let Expr::Path(path) = name_value.value.clone() {
-                        args.plugin = Some(path);
+                        args.set = Some(path);
                     }
                     else {
-                        return Err(input.error("Expected a Plugin after \"plugin\""));
+                        return Err(input.error("Expected a SystemSet after \"set\""));
+                    }
+                },
+                "on_enter" => {
+                    if args.on_enter.is_some() {
+                        return Err(input.error("\"on_enter\" defined more than once"));
+                    }
+                    args.on_enter = Some(name_value.value.clone());
+                },
+                "on_exit" => {
+                    if args.on_exit.is_some() {
+                        return Err(input.error("\"on_exit\" defined more than once"));
                     }
+                    args.on_exit = Some(name_value.value.clone());
                 },
                 _ => {
                     // Any other attributes, assume they're transformers for the system
@@ -84,9 +113,20 @@ impl SystemArgs {
         Self {
             plugin: new_args.plugin.clone().or(self.plugin.clone()),
             schedule: new_args.schedule.clone().or(self.schedule.clone()),
+            phase: new_args.phase.clone().or(self.phase.clone()),
+            set: new_args.set.clone().or(self.set.clone()),
+            on_enter: new_args.on_enter.clone().or(self.on_enter.clone()),
+            on_exit: new_args.on_exit.clone().or(self.on_exit.clone()),
             transforms: [self.transforms.clone(), new_args.transforms.clone()].concat(),
         }
     }
+
+    /// Returns the args as a comma-separated stream of `key = value` metas,
+    /// suitable for embedding in another attribute's tokens (e.g. `config_systems!`'s
+    /// `_butler_config_systems_defaults` marker).
+    pub fn get_metas(&self) -> TokenStream {
+        self.to_token_stream()
+    }
 }
 
 impl ToTokens for SystemArgs {
@@ -97,6 +137,18 @@ impl ToTokens for SystemArgs {
         if let Some(value) = self.schedule.clone() {
             tokens.extend(quote! { schedule = #value, });
         }
+        if let Some(value) = self.phase.clone() {
+            tokens.extend(quote! { phase = #value, });
+        }
+        if let Some(value) = self.set.clone() {
+            tokens.extend(quote! { set = #value, });
+        }
+        if let Some(value) = self.on_enter.clone() {
+            tokens.extend(quote! { on_enter = #value, });
+        }
+        if let Some(value) = self.on_exit.clone() {
+            tokens.extend(quote! { on_exit = #value, });
+        }
         for (path, value) in &self.transforms {
             tokens.extend(quote! { #path = #value, });
         }
@@ -120,26 +172,48 @@ impl ToTokens for SystemArgs {
 /// # }
 /// #
 /// ```
+///
+/// Passing `phase = finish` or `phase = cleanup` defers the registration to the
+/// plugin's `Plugin::finish`/`Plugin::cleanup` instead of `Plugin::build`, which is
+/// useful for systems that depend on resources only available once every plugin in
+/// the app has finished its `build` pass. The default phase is `build`.
+///
+/// `on_enter = GameState::Menu` / `on_exit = GameState::Menu` are shorthand for
+/// `schedule = OnEnter(GameState::Menu)` / `schedule = OnExit(GameState::Menu)` and
+/// cannot be combined with an explicit `schedule` or with each other. The state
+/// itself must still be registered with the app, e.g. via `#[butler_state]`.
 pub(crate) fn system_free_standing_impl(args: SystemArgs, item: ItemFn) -> Result<proc_macro2::TokenStream, proc_macro2::TokenStream> {
-    let schedule = args.schedule
-        .ok_or_else(|| Error::new(Span::call_site(), "#[system] requires either a defined or inherited `schedule`").into_compile_error())?;
-    let plugin = args.plugin
-        .ok_or_else(|| Error::new(Span::call_site(), "#[system] requires either a defined or inherited `plugin`").into_compile_error())?;
-
     let bevy_butler = get_crate("bevy-butler")
         .map_err(|e| Error::new(Span::call_site(), e).to_compile_error())?;
 
+    let schedule = match (args.schedule, args.on_enter, args.on_exit) {
+        (Some(schedule), None, None) => schedule.into_token_stream(),
+        (None, Some(state), None) => quote! { #bevy_butler::__internal::bevy_state::state::OnEnter(#state) },
+        (None, None, Some(state)) => quote! { #bevy_butler::__internal::bevy_state::state::OnExit(#state) },
+        (None, None, None) => return Err(Error::new(Span::call_site(), "#[system] requires either a defined or inherited `schedule`, `on_enter`, or `on_exit`").into_compile_error()),
+        _ => return Err(Error::new(Span::call_site(), "#[system] only accepts one of \"schedule\", \"on_enter\", \"on_exit\"").into_compile_error()),
+    };
+    let plugin = args.plugin
+        .ok_or_else(|| Error::new(Span::call_site(), "#[system] requires either a defined or inherited `plugin`").into_compile_error())?;
+    let phase = args.phase
+        .unwrap_or_else(|| format_ident!("build"));
+    let phase_variant = format_ident!("{}", to_pascal_case(&phase.to_string()));
+
     let sys_name = &item.sig.ident;
 
-    let transforms = if args.transforms.is_empty() {
-        None
-    } else {
-        let transform_iter = args.transforms
+    let transform_iter = args.transforms
         .into_iter()
-        .map(|(path, expr)| quote! { #path(#expr) });
+        .map(|(path, expr)| quote! { #path(#expr) })
+        .chain(args.set.map(|set| quote! { in_set(#set) }));
+    let transforms = {
         let mut transforms = quote! { . };
-        transforms.append_separated(transform_iter, Token![.](Span::call_site()));
-        Some(transforms)
+        let mut peekable = transform_iter.peekable();
+        if peekable.peek().is_none() {
+            None
+        } else {
+            transforms.append_separated(peekable, Token![.](Span::call_site()));
+            Some(transforms)
+        }
     };
 
     let butler_func_name = format_ident!("__butler_{}", sys_name);
@@ -151,9 +225,18 @@ pub(crate) fn system_free_standing_impl(args: SystemArgs, item: ItemFn) -> Resul
         #[linkme(crate = #bevy_butler::__internal::linkme)] // I LOVE UNDOCUMENTED ATTRIBUTES!!! FUCK!!!
         #[allow(non_upper_case_globals)]
         static #butler_func_name: #bevy_butler::__internal::ButlerFunc = |registry: &mut #bevy_butler::__internal::ButlerRegistry| {
-            registry.entry(std::any::TypeId::of::<#plugin>())
+            registry.entry((std::any::TypeId::of::<#plugin>(), #bevy_butler::__internal::ButlerPhase::#phase_variant))
                 .or_default()
                 .push(|app| { app.add_systems( #schedule, #sys_name #transforms ); } );
         };
     }.into())
+}
+
+/// `build` -> `Build`, `finish` -> `Finish`, `cleanup` -> `Cleanup`
+pub(crate) fn to_pascal_case(phase: &str) -> String {
+    let mut chars = phase.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
 }
\ No newline at end of file
@@ -0,0 +1,40 @@
+use bevy_app::prelude::*;
+use bevy_butler::*;
+use bevy_ecs::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use super::common::log_plugin;
+
+#[derive(Resource, Default)]
+struct Order(Vec<&'static str>);
+
+trait OrderExt {
+    fn push_order(&mut self, name: &'static str);
+}
+
+impl OrderExt for App {
+    fn push_order(&mut self, name: &'static str) {
+        self.world_mut().get_resource_or_insert_with(Order::default).0.push(name);
+    }
+}
+
+#[butler_plugin(build = push_order("A"))]
+struct PluginA;
+
+#[butler_plugin(build = push_order("B"))]
+struct PluginB;
+
+#[butler_plugin(build = push_order("C"))]
+struct PluginC;
+
+#[butler_plugin_group(PluginA, PluginB, add_before(PluginB, PluginC), disable(PluginA))]
+struct MyGroup;
+
+#[wasm_bindgen_test(unsupported = test)]
+pub fn add_before_and_disable_reorder_the_group() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyGroup);
+
+    // `PluginA` was disabled, and `PluginC` was inserted before `PluginB`
+    assert_eq!(app.world().resource::<Order>().0, vec!["C", "B"]);
+}
@@ -0,0 +1,62 @@
+use bevy_app::prelude::*;
+use bevy_butler::*;
+use bevy_ecs::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use super::common::log_plugin;
+
+#[derive(Resource, Default)]
+struct Marks(u8);
+
+trait MarkExt {
+    fn mark(&mut self, bit: u8);
+}
+
+impl MarkExt for App {
+    fn mark(&mut self, bit: u8) {
+        self.world_mut().get_resource_or_insert_with(Marks::default).0 |= bit;
+    }
+}
+
+#[butler_plugin(build = mark(1), finish = mark(2), cleanup = mark(4))]
+struct HookedPlugin;
+
+#[wasm_bindgen_test(unsupported = test)]
+pub fn build_finish_cleanup_all_run() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(HookedPlugin);
+    assert_eq!(app.world().resource::<Marks>().0, 1);
+
+    app.finish();
+    assert_eq!(app.world().resource::<Marks>().0, 1 | 2);
+
+    app.cleanup();
+    assert_eq!(app.world().resource::<Marks>().0, 1 | 2 | 4);
+}
+
+#[derive(Resource, Default)]
+struct Ran(bool);
+
+#[butler_plugin]
+struct DeferredPlugin;
+
+#[system(plugin = DeferredPlugin, schedule = Update, phase = finish)]
+fn mark_ran(mut ran: ResMut<Ran>) {
+    ran.0 = true;
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+pub fn phase_finish_defers_registration_until_finish() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin())
+        .add_plugins(DeferredPlugin)
+        .init_resource::<Ran>();
+
+    // `mark_ran` is registered at `phase = finish`, so it isn't added to `Update` yet
+    app.update();
+    assert!(!app.world().resource::<Ran>().0);
+
+    app.finish();
+    app.update();
+    assert!(app.world().resource::<Ran>().0);
+}
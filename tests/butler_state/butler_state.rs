@@ -0,0 +1,44 @@
+use bevy_app::prelude::*;
+use bevy_butler::*;
+use bevy_ecs::prelude::*;
+use bevy_state::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use super::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(States, Debug, Default, Hash, Eq, PartialEq, Clone)]
+#[butler_state(plugin = MyPlugin)]
+enum GameState {
+    #[default]
+    Menu,
+    Playing,
+}
+
+#[derive(Resource, Default)]
+struct EnteredPlaying(bool);
+
+#[system(plugin = MyPlugin, on_enter = GameState::Playing)]
+fn mark_entered(mut entered: ResMut<EnteredPlaying>) {
+    entered.0 = true;
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+pub fn on_enter_runs_after_state_transition() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin())
+        .add_plugins(MyPlugin)
+        .init_resource::<EnteredPlaying>();
+
+    app.update();
+    assert!(!app.world().resource::<EnteredPlaying>().0);
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Playing);
+    app.update();
+
+    assert!(app.world().resource::<EnteredPlaying>().0);
+}
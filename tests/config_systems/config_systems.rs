@@ -0,0 +1,44 @@
+use bevy_app::prelude::*;
+use bevy_butler::*;
+use bevy_ecs::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use super::common::log_plugin;
+
+#[derive(Resource, Default)]
+struct Order(Vec<&'static str>);
+
+#[butler_plugin]
+struct MyPlugin;
+
+// Named out of their execution order on purpose: a chained group must run its
+// systems in source order regardless of how they're named or what Bevy's default
+// scheduler would otherwise pick.
+config_systems!(chain, schedule = Update, plugin = MyPlugin;
+    #[system]
+    fn third(mut order: ResMut<Order>) {
+        order.0.push("third");
+    }
+
+    #[system]
+    fn second(mut order: ResMut<Order>) {
+        order.0.push("second");
+    }
+
+    #[system]
+    fn first(mut order: ResMut<Order>) {
+        order.0.push("first");
+    }
+);
+
+#[wasm_bindgen_test(unsupported = test)]
+pub fn chain_preserves_source_order() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin())
+        .add_plugins(MyPlugin)
+        .init_resource::<Order>();
+
+    app.update();
+
+    assert_eq!(app.world().resource::<Order>().0, vec!["third", "second", "first"]);
+}
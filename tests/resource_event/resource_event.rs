@@ -0,0 +1,38 @@
+use bevy_app::prelude::*;
+use bevy_butler::*;
+use bevy_ecs::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use super::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+#[resource(plugin = MyPlugin, init)]
+struct Score(pub u32);
+
+#[derive(Event)]
+#[event(plugin = MyPlugin)]
+struct Scored(pub u32);
+
+#[system(plugin = MyPlugin, schedule = Update)]
+fn tally_scores(mut score: ResMut<Score>, mut events: EventReader<Scored>) {
+    for event in events.read() {
+        score.0 += event.0;
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+pub fn resource_and_event_are_registered() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin())
+        .add_plugins(MyPlugin)
+        .add_systems(Startup, |mut events: EventWriter<Scored>| {
+            events.send(Scored(3));
+        });
+
+    app.update();
+
+    assert_eq!(app.world().resource::<Score>().0, 3);
+}